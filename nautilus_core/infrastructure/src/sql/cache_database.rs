@@ -15,19 +15,31 @@
 
 use std::{
     collections::{HashMap, VecDeque},
+    future::Future,
+    sync::{Arc, Mutex},
     time::{Duration, Instant},
 };
 
+use async_trait::async_trait;
+use nautilus_core::time::UnixNanos;
 use nautilus_model::{
-    identifiers::instrument_id::InstrumentId, instruments::InstrumentAny, types::currency::Currency,
+    data::quote::QuoteTick, identifiers::instrument_id::InstrumentId, instruments::InstrumentAny,
+    types::currency::Currency,
 };
+use serde::Serialize;
 use sqlx::{postgres::PgConnectOptions, PgPool};
+use thiserror::Error;
 use tokio::{
-    sync::mpsc::{channel, error::TryRecvError, Receiver, Sender},
+    sync::{
+        mpsc::{channel, error::TryRecvError, error::TrySendError, Receiver, Sender},
+        oneshot,
+    },
     time::sleep,
 };
 
 use crate::sql::{
+    database::CacheDatabase,
+    merkle::{self, MerkleTree},
     models::general::GeneralRow,
     pg::{connect_pg, get_postgres_connect_options},
     queries::DatabaseQueries,
@@ -41,6 +53,12 @@ use crate::sql::{
 pub struct PostgresCacheDatabase {
     pub pool: PgPool,
     tx: Sender<DatabaseQuery>,
+    control_tx: Sender<ControlMessage>,
+    /// Present only when the caller opted into the Merkle integrity subsystem via
+    /// [`PostgresCacheDatabase::connect`]; shared with the background drain task so both the
+    /// writer and [`PostgresCacheDatabase::merkle_root`]/[`PostgresCacheDatabase::merkle_proof`]
+    /// see the same tree.
+    merkle: Option<Arc<Mutex<MerkleTree>>>,
 }
 
 #[allow(clippy::large_enum_variant)]
@@ -49,81 +67,262 @@ pub enum DatabaseQuery {
     Add(String, Vec<u8>),
     AddCurrency(Currency),
     AddInstrument(InstrumentAny),
+    AddQuoteTick(QuoteTick),
 }
 
-fn get_buffer_interval() -> Duration {
-    Duration::from_millis(0)
+/// Out-of-band requests to the background drain task that aren't themselves persisted rows.
+enum ControlMessage {
+    /// Respond once the buffer this message was received alongside has been fully drained.
+    Flush(oneshot::Sender<()>),
 }
 
-async fn drain_buffer(pool: &PgPool, buffer: &mut VecDeque<DatabaseQuery>) {
-    for cmd in buffer.drain(..) {
-        match cmd {
-            DatabaseQuery::Add(key, value) => {
-                DatabaseQueries::add(pool, key, value).await.unwrap();
+/// Errors surfaced directly to callers rather than being retried or dead-lettered in the
+/// background, so they can back off or fail fast instead of awaiting indefinitely.
+#[derive(Debug, Error)]
+pub enum CacheDatabaseError {
+    /// The bounded channel to the background drain task is full.
+    #[error("cache database write channel is saturated, try again once the backlog drains")]
+    Saturated,
+    /// The background drain task has shut down; no further writes can be accepted.
+    #[error("cache database message handler has shut down")]
+    Closed,
+}
+
+/// Default interval between drain cycles when the caller doesn't override it via [`PostgresCacheDatabase::connect`].
+const DEFAULT_BUFFER_INTERVAL_MS: u64 = 0;
+
+/// Default number of buffered queries that forces an out-of-cycle drain, so a burst doesn't
+/// grow the in-memory buffer unbounded while waiting for `buffer_interval` to elapse.
+const DEFAULT_DRAIN_SIZE: usize = 1_000;
+
+/// Maximum attempts for a single batch group before it is moved to the `dead_letter` table.
+const MAX_DRAIN_ATTEMPTS: u32 = 5;
+
+/// Base delay for bounded exponential backoff between drain retry attempts.
+const RETRY_BASE_DELAY: Duration = Duration::from_millis(50);
+
+fn get_buffer_interval(buffer_interval_ms: Option<u64>) -> Duration {
+    Duration::from_millis(buffer_interval_ms.unwrap_or(DEFAULT_BUFFER_INTERVAL_MS))
+}
+
+/// Queues `query` on `tx` without blocking, surfacing channel saturation or shutdown as a typed
+/// [`CacheDatabaseError`] rather than awaiting indefinitely for room in the buffer.
+fn send_query(tx: &Sender<DatabaseQuery>, query: DatabaseQuery) -> anyhow::Result<()> {
+    tx.try_send(query).map_err(|err| match err {
+        TrySendError::Full(_) => CacheDatabaseError::Saturated.into(),
+        TrySendError::Closed(_) => CacheDatabaseError::Closed.into(),
+    })
+}
+
+/// Runs `op` against `rows` with bounded exponential backoff. On success returns `Ok(())`; once
+/// [`MAX_DRAIN_ATTEMPTS`] is exhausted, `rows` is serialized and moved to the `dead_letter` table
+/// instead of panicking the drain task, so one bad batch can't block every other kind. `op`'s
+/// error type is `anyhow::Error` rather than `sqlx::Error` so a batch that fails to even
+/// serialize (not just a database error) still retries and eventually dead-letters instead of
+/// silently persisting a nulled-out payload.
+async fn drain_group_with_retry<T, F, Fut>(pool: &PgPool, kind: &str, rows: Vec<T>, op: F)
+where
+    T: Clone + Serialize,
+    F: Fn(PgPool, Vec<T>) -> Fut,
+    Fut: Future<Output = anyhow::Result<()>>,
+{
+    let mut attempt = 0;
+    loop {
+        match op(pool.clone(), rows.clone()).await {
+            Ok(()) => return,
+            Err(err) if attempt + 1 >= MAX_DRAIN_ATTEMPTS => {
+                tracing::error!(
+                    "Drain of {} `{kind}` row(s) failed after {MAX_DRAIN_ATTEMPTS} attempts, \
+                     moving to dead_letter: {err}",
+                    rows.len()
+                );
+                move_to_dead_letter(pool, kind, &rows).await;
+                return;
             }
-            DatabaseQuery::AddCurrency(currency) => {
-                DatabaseQueries::add_currency(pool, currency).await.unwrap();
+            Err(err) => {
+                let delay = RETRY_BASE_DELAY * 2u32.pow(attempt);
+                tracing::warn!(
+                    "Drain of `{kind}` failed (attempt {}/{MAX_DRAIN_ATTEMPTS}), retrying in {delay:?}: {err}",
+                    attempt + 1
+                );
+                sleep(delay).await;
+                attempt += 1;
             }
-            DatabaseQuery::AddInstrument(instrument) => match instrument {
-                InstrumentAny::CryptoFuture(crypto_future) => {
-                    DatabaseQueries::add_instrument(pool, "CRYPTO_FUTURE", Box::new(crypto_future))
-                        .await
-                        .unwrap()
-                }
-                InstrumentAny::CryptoPerpetual(crypto_perpetual) => {
-                    DatabaseQueries::add_instrument(
-                        pool,
-                        "CRYPTO_PERPETUAL",
-                        Box::new(crypto_perpetual),
-                    )
-                    .await
-                    .unwrap()
-                }
-                InstrumentAny::CurrencyPair(currency_pair) => {
-                    DatabaseQueries::add_instrument(pool, "CURRENCY_PAIR", Box::new(currency_pair))
-                        .await
-                        .unwrap()
-                }
-                InstrumentAny::Equity(equity) => {
-                    DatabaseQueries::add_instrument(pool, "EQUITY", Box::new(equity))
-                        .await
-                        .unwrap()
-                }
-                InstrumentAny::FuturesContract(futures_contract) => {
-                    DatabaseQueries::add_instrument(
-                        pool,
-                        "FUTURES_CONTRACT",
-                        Box::new(futures_contract),
-                    )
-                    .await
-                    .unwrap()
-                }
-                InstrumentAny::FuturesSpread(futures_spread) => DatabaseQueries::add_instrument(
-                    pool,
-                    "FUTURES_SPREAD",
-                    Box::new(futures_spread),
-                )
-                .await
-                .unwrap(),
-                InstrumentAny::OptionsContract(options_contract) => {
-                    DatabaseQueries::add_instrument(
-                        pool,
-                        "OPTIONS_CONTRACT",
-                        Box::new(options_contract),
-                    )
-                    .await
-                    .unwrap()
+        }
+    }
+}
+
+/// Serializes `rows` and appends them to the `dead_letter` table under `kind`, so a batch that
+/// never succeeds is preserved for inspection/replay rather than silently dropped.
+async fn move_to_dead_letter<T: Serialize>(pool: &PgPool, kind: &str, rows: &[T]) {
+    match serde_json::to_vec(rows) {
+        Ok(payload) => {
+            if let Err(err) = DatabaseQueries::add_dead_letter(pool, kind, payload).await {
+                tracing::error!("Failed to persist `{kind}` rows to dead_letter: {err}");
+            }
+        }
+        Err(err) => {
+            tracing::error!("Failed to serialize `{kind}` rows for dead_letter: {err}");
+        }
+    }
+}
+
+/// Drains the buffered `general` key/value pairs, folding them into the Merkle integrity tree
+/// (when enabled) and persisting the updated leaf hashes atomically alongside the rows, with the
+/// same retry-then-dead-letter behavior as [`drain_group_with_retry`]. The root itself is not
+/// stored; it is recomputed from the persisted leaf hashes.
+async fn drain_general_with_retry(
+    pool: &PgPool,
+    rows: Vec<(String, Vec<u8>)>,
+    merkle: Option<&Mutex<MerkleTree>>,
+) {
+    let mut attempt = 0;
+    loop {
+        // Compute the prospective tree update against a private clone of the currently
+        // *committed* tree, so a failed or still-retrying attempt never lets uncommitted leaves
+        // leak into the tree `merkle_root`/`merkle_proof` observe: if this batch is ultimately
+        // dead-lettered, the clone is simply dropped and the shared tree never learns of it.
+        let pending_merkle = merkle.map(|merkle| {
+            let mut tree = merkle.lock().expect("Merkle tree lock poisoned").clone();
+            for (key, value) in &rows {
+                tree.insert(key.clone(), value);
+            }
+            let leaves: Vec<(String, merkle::Hash)> = rows
+                .iter()
+                .filter_map(|(key, _)| tree.leaf_hash(key).map(|hash| (key.clone(), hash)))
+                .collect();
+            (tree, leaves)
+        });
+
+        let attempt_result: Result<(), sqlx::Error> = async {
+            let mut tx = pool.begin().await?;
+
+            if let Some((_, leaves)) = &pending_merkle {
+                DatabaseQueries::persist_merkle_state(&mut tx, leaves.clone()).await?;
+            }
+
+            DatabaseQueries::copy_general_batch(&mut tx, rows.clone()).await?;
+            tx.commit().await
+        }
+        .await;
+
+        match attempt_result {
+            Ok(()) => {
+                // Only now, after the rows and leaf hashes are durably committed together, fold
+                // the update into the tree callers actually see.
+                if let (Some(merkle), Some((tree, _))) = (merkle, pending_merkle) {
+                    *merkle.lock().expect("Merkle tree lock poisoned") = tree;
                 }
-                InstrumentAny::OptionsSpread(options_spread) => DatabaseQueries::add_instrument(
-                    pool,
-                    "OPTIONS_SPREAD",
-                    Box::new(options_spread),
-                )
-                .await
-                .unwrap(),
+                return;
+            }
+            Err(err) if attempt + 1 >= MAX_DRAIN_ATTEMPTS => {
+                tracing::error!(
+                    "Drain of {} `general` row(s) failed after {MAX_DRAIN_ATTEMPTS} attempts, \
+                     moving to dead_letter: {err}",
+                    rows.len()
+                );
+                move_to_dead_letter(pool, "general", &rows).await;
+                return;
+            }
+            Err(err) => {
+                let delay = RETRY_BASE_DELAY * 2u32.pow(attempt);
+                tracing::warn!(
+                    "Drain of `general` failed (attempt {}/{MAX_DRAIN_ATTEMPTS}), retrying in {delay:?}: {err}",
+                    attempt + 1
+                );
+                sleep(delay).await;
+                attempt += 1;
+            }
+        }
+    }
+}
+
+/// Drains every query currently in `buffer`, grouping them by kind so each group lands as one
+/// multi-row `INSERT ... ON CONFLICT` (the `general` table included). Each group is
+/// drained independently with its own retry/dead-letter handling, so a persistently failing
+/// group can't block the others or the drain task as a whole.
+///
+/// Note this means a drain cycle no longer commits as a single atomic transaction across
+/// kinds: `general` commits in its own transaction (see [`drain_general_with_retry`]), and each
+/// of currency/instrument/quote commits independently via [`drain_group_with_retry`]. A cycle
+/// that writes currencies, instruments, and quotes together can have some of those groups land
+/// and others dead-letter. That's the cost of giving each kind its own retry/dead-letter path
+/// instead of one kind's persistent failure blocking (or rolling back) every other kind's
+/// writes; within a single kind, its own batch still commits atomically.
+async fn drain_buffer(
+    pool: &PgPool,
+    buffer: &mut VecDeque<DatabaseQuery>,
+    merkle: Option<&Mutex<MerkleTree>>,
+) {
+    if buffer.is_empty() {
+        return;
+    }
+
+    let mut general = Vec::new();
+    let mut currencies = Vec::new();
+    let mut crypto_futures = Vec::new();
+    let mut crypto_perpetuals = Vec::new();
+    let mut currency_pairs = Vec::new();
+    let mut equities = Vec::new();
+    let mut futures_contracts = Vec::new();
+    let mut futures_spreads = Vec::new();
+    let mut options_contracts = Vec::new();
+    let mut options_spreads = Vec::new();
+    let mut quote_ticks = Vec::new();
+
+    for cmd in buffer.drain(..) {
+        match cmd {
+            DatabaseQuery::Add(key, value) => general.push((key, value)),
+            DatabaseQuery::AddCurrency(currency) => currencies.push(currency),
+            DatabaseQuery::AddInstrument(instrument) => match instrument {
+                InstrumentAny::CryptoFuture(i) => crypto_futures.push(i),
+                InstrumentAny::CryptoPerpetual(i) => crypto_perpetuals.push(i),
+                InstrumentAny::CurrencyPair(i) => currency_pairs.push(i),
+                InstrumentAny::Equity(i) => equities.push(i),
+                InstrumentAny::FuturesContract(i) => futures_contracts.push(i),
+                InstrumentAny::FuturesSpread(i) => futures_spreads.push(i),
+                InstrumentAny::OptionsContract(i) => options_contracts.push(i),
+                InstrumentAny::OptionsSpread(i) => options_spreads.push(i),
             },
+            DatabaseQuery::AddQuoteTick(quote) => quote_ticks.push(quote),
         }
     }
+
+    if !general.is_empty() {
+        drain_general_with_retry(pool, general, merkle).await;
+    }
+    if !currencies.is_empty() {
+        drain_group_with_retry(pool, "currency", currencies, |pool, rows| async move {
+            DatabaseQueries::add_currency_batch(&pool, rows).await
+        })
+        .await;
+    }
+
+    macro_rules! drain_instrument_batch {
+        ($rows:expr, $kind:literal) => {
+            if !$rows.is_empty() {
+                drain_group_with_retry(pool, $kind, $rows, |pool, rows| async move {
+                    DatabaseQueries::add_instrument_batch(&pool, $kind, rows).await
+                })
+                .await;
+            }
+        };
+    }
+    drain_instrument_batch!(crypto_futures, "CRYPTO_FUTURE");
+    drain_instrument_batch!(crypto_perpetuals, "CRYPTO_PERPETUAL");
+    drain_instrument_batch!(currency_pairs, "CURRENCY_PAIR");
+    drain_instrument_batch!(equities, "EQUITY");
+    drain_instrument_batch!(futures_contracts, "FUTURES_CONTRACT");
+    drain_instrument_batch!(futures_spreads, "FUTURES_SPREAD");
+    drain_instrument_batch!(options_contracts, "OPTIONS_CONTRACT");
+    drain_instrument_batch!(options_spreads, "OPTIONS_SPREAD");
+
+    if !quote_ticks.is_empty() {
+        drain_group_with_retry(pool, "quote_tick", quote_ticks, |pool, rows| async move {
+            DatabaseQueries::add_quote_batch(&pool, rows).await
+        })
+        .await;
+    }
 }
 
 impl PostgresCacheDatabase {
@@ -133,43 +332,95 @@ impl PostgresCacheDatabase {
         username: Option<String>,
         password: Option<String>,
         database: Option<String>,
+        buffer_interval_ms: Option<u64>,
+        drain_size: Option<usize>,
+        enable_merkle_integrity: bool,
     ) -> Result<Self, sqlx::Error> {
         let pg_connect_options =
             get_postgres_connect_options(host, port, username, password, database).unwrap();
         let pool = connect_pg(pg_connect_options.clone().into()).await.unwrap();
         let (tx, rx) = channel::<DatabaseQuery>(1000);
+        let (control_tx, control_rx) = channel::<ControlMessage>(32);
+        let drain_size = drain_size.unwrap_or(DEFAULT_DRAIN_SIZE);
+        let merkle = if enable_merkle_integrity {
+            // Rebuild from whatever was persisted in prior process lifetimes, rather than
+            // starting empty, so `merkle_root`/`merkle_proof` cover every row ever durably
+            // written to `general`, not just rows written since this process started.
+            let leaves = DatabaseQueries::load_merkle_state(&pool).await.unwrap();
+            Some(Arc::new(Mutex::new(MerkleTree::from_leaf_hashes(leaves))))
+        } else {
+            None
+        };
+        let merkle_for_drain = merkle.clone();
         // spawn a thread to handle messages
         let _join_handle = tokio::spawn(async move {
-            PostgresCacheDatabase::handle_message(rx, pg_connect_options.clone().into()).await;
+            PostgresCacheDatabase::handle_message(
+                rx,
+                control_rx,
+                pg_connect_options.clone().into(),
+                buffer_interval_ms,
+                drain_size,
+                merkle_for_drain,
+            )
+            .await;
         });
-        Ok(PostgresCacheDatabase { pool, tx })
+        Ok(PostgresCacheDatabase {
+            pool,
+            tx,
+            control_tx,
+            merkle,
+        })
     }
 
-    async fn handle_message(mut rx: Receiver<DatabaseQuery>, pg_connect_options: PgConnectOptions) {
+    async fn handle_message(
+        mut rx: Receiver<DatabaseQuery>,
+        mut control_rx: Receiver<ControlMessage>,
+        pg_connect_options: PgConnectOptions,
+        buffer_interval_ms: Option<u64>,
+        drain_size: usize,
+        merkle: Option<Arc<Mutex<MerkleTree>>>,
+    ) {
         let pool = connect_pg(pg_connect_options).await.unwrap();
         // Buffering
         let mut buffer: VecDeque<DatabaseQuery> = VecDeque::new();
+        let mut pending_flushes: Vec<oneshot::Sender<()>> = Vec::new();
         let mut last_drain = Instant::now();
-        let buffer_interval = get_buffer_interval();
+        let buffer_interval = get_buffer_interval(buffer_interval_ms);
         let recv_interval = Duration::from_millis(1);
 
         loop {
-            if last_drain.elapsed() >= buffer_interval && !buffer.is_empty() {
+            if let Ok(ControlMessage::Flush(respond)) = control_rx.try_recv() {
+                pending_flushes.push(respond);
+            }
+
+            let due_by_time = last_drain.elapsed() >= buffer_interval && !buffer.is_empty();
+            let due_by_size = buffer.len() >= drain_size;
+            if due_by_time || due_by_size {
                 // drain buffer
-                drain_buffer(&pool, &mut buffer).await;
+                drain_buffer(&pool, &mut buffer, merkle.as_deref()).await;
                 last_drain = Instant::now();
             } else {
                 // Continue to receive and handle messages until channel is hung up
                 match rx.try_recv() {
                     Ok(msg) => buffer.push_back(msg),
-                    Err(TryRecvError::Empty) => sleep(recv_interval).await,
+                    Err(TryRecvError::Empty) => {
+                        if buffer.is_empty() {
+                            for respond in pending_flushes.drain(..) {
+                                let _ = respond.send(());
+                            }
+                        }
+                        sleep(recv_interval).await;
+                    }
                     Err(TryRecvError::Disconnected) => break,
                 }
             }
         }
-        // rain any remaining message
+        // Drain any remaining messages
         if !buffer.is_empty() {
-            drain_buffer(&pool, &mut buffer).await;
+            drain_buffer(&pool, &mut buffer, merkle.as_deref()).await;
+        }
+        for respond in pending_flushes.drain(..) {
+            let _ = respond.send(());
         }
     }
 
@@ -190,18 +441,20 @@ impl PostgresCacheDatabase {
         }
     }
 
+    /// Queues `(key, value)` for persistence.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CacheDatabaseError::Saturated`] immediately if the write channel is full,
+    /// rather than waiting indefinitely for space to free up, and
+    /// [`CacheDatabaseError::Closed`] if the background drain task has shut down.
     pub async fn add(&self, key: String, value: Vec<u8>) -> anyhow::Result<()> {
-        let query = DatabaseQuery::Add(key, value);
-        self.tx.send(query).await.map_err(|err| {
-            anyhow::anyhow!("Failed to send query to database message handler: {err}")
-        })
+        send_query(&self.tx, DatabaseQuery::Add(key, value))
     }
 
+    /// Queues `currency` for persistence. See [`PostgresCacheDatabase::add`] for error semantics.
     pub async fn add_currency(&self, currency: Currency) -> anyhow::Result<()> {
-        let query = DatabaseQuery::AddCurrency(currency);
-        self.tx.send(query).await.map_err(|err| {
-            anyhow::anyhow!("Failed to query add_currency to database message handler: {err}")
-        })
+        send_query(&self.tx, DatabaseQuery::AddCurrency(currency))
     }
 
     pub async fn load_currencies(&self) -> anyhow::Result<Vec<Currency>> {
@@ -212,13 +465,9 @@ impl PostgresCacheDatabase {
         DatabaseQueries::load_currency(&self.pool, code).await
     }
 
+    /// Queues `instrument` for persistence. See [`PostgresCacheDatabase::add`] for error semantics.
     pub async fn add_instrument(&self, instrument: InstrumentAny) -> anyhow::Result<()> {
-        let query = DatabaseQuery::AddInstrument(instrument);
-        self.tx.send(query).await.map_err(|err| {
-            anyhow::anyhow!(
-                "Failed to send query add_instrument to database message handler: {err}"
-            )
-        })
+        send_query(&self.tx, DatabaseQuery::AddInstrument(instrument))
     }
 
     pub async fn load_instrument(
@@ -231,4 +480,152 @@ impl PostgresCacheDatabase {
     pub async fn load_instruments(&self) -> anyhow::Result<Vec<InstrumentAny>> {
         DatabaseQueries::load_instruments(&self.pool).await
     }
+
+    /// Queues `quote` for persistence. See [`PostgresCacheDatabase::add`] for error semantics.
+    pub async fn add_quote(&self, quote: QuoteTick) -> anyhow::Result<()> {
+        send_query(&self.tx, DatabaseQuery::AddQuoteTick(quote))
+    }
+
+    /// Loads every `quote_tick` row for `instrument_id` with `ts_event` in `[start_ns, end_ns]`,
+    /// ordered by `ts_event` ascending.
+    pub async fn load_quotes(
+        &self,
+        instrument_id: &InstrumentId,
+        start_ns: UnixNanos,
+        end_ns: UnixNanos,
+    ) -> anyhow::Result<Vec<QuoteTick>> {
+        DatabaseQueries::load_quotes(&self.pool, instrument_id, start_ns, end_ns).await
+    }
+
+    /// Returns the current root of the Merkle integrity tree over the `general` table.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the Merkle integrity subsystem was not enabled via
+    /// [`PostgresCacheDatabase::connect`], or if nothing has been written yet.
+    pub fn merkle_root(&self) -> anyhow::Result<merkle::Hash> {
+        let tree = self
+            .merkle
+            .as_ref()
+            .ok_or_else(|| anyhow::anyhow!("Merkle integrity subsystem is not enabled"))?
+            .lock()
+            .expect("Merkle tree lock poisoned");
+        tree.root()
+            .ok_or_else(|| anyhow::anyhow!("Merkle tree is empty"))
+    }
+
+    /// Returns the Merkle inclusion proof for `key`: the sibling hashes and left/right flags
+    /// along the path from its leaf up to [`PostgresCacheDatabase::merkle_root`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the Merkle integrity subsystem was not enabled via
+    /// [`PostgresCacheDatabase::connect`], or if `key` has never been written.
+    pub fn merkle_proof(&self, key: &str) -> anyhow::Result<Vec<merkle::ProofStep>> {
+        let tree = self
+            .merkle
+            .as_ref()
+            .ok_or_else(|| anyhow::anyhow!("Merkle integrity subsystem is not enabled"))?
+            .lock()
+            .expect("Merkle tree lock poisoned");
+        tree.proof(key)
+            .ok_or_else(|| anyhow::anyhow!("No Merkle leaf found for key `{key}`"))
+    }
+
+    /// Blocks until every query queued so far has been drained (including any currently
+    /// in-flight retries), so callers can guarantee durability at shutdown.
+    pub async fn flush(&self) -> anyhow::Result<()> {
+        let (respond_tx, respond_rx) = oneshot::channel();
+        self.control_tx
+            .send(ControlMessage::Flush(respond_tx))
+            .await
+            .map_err(|_| anyhow::Error::from(CacheDatabaseError::Closed))?;
+        respond_rx
+            .await
+            .map_err(|_| anyhow::Error::from(CacheDatabaseError::Closed))
+    }
+}
+
+#[async_trait]
+impl CacheDatabase for PostgresCacheDatabase {
+    async fn load(&self) -> anyhow::Result<HashMap<String, Vec<u8>>> {
+        Ok(PostgresCacheDatabase::load(self).await?)
+    }
+
+    async fn add(&self, key: String, value: Vec<u8>) -> anyhow::Result<()> {
+        PostgresCacheDatabase::add(self, key, value).await
+    }
+
+    async fn add_currency(&self, currency: Currency) -> anyhow::Result<()> {
+        PostgresCacheDatabase::add_currency(self, currency).await
+    }
+
+    async fn load_currency(&self, code: &str) -> anyhow::Result<Option<Currency>> {
+        PostgresCacheDatabase::load_currency(self, code).await
+    }
+
+    async fn load_currencies(&self) -> anyhow::Result<Vec<Currency>> {
+        PostgresCacheDatabase::load_currencies(self).await
+    }
+
+    async fn add_instrument(&self, instrument: InstrumentAny) -> anyhow::Result<()> {
+        PostgresCacheDatabase::add_instrument(self, instrument).await
+    }
+
+    async fn load_instrument(
+        &self,
+        instrument_id: InstrumentId,
+    ) -> anyhow::Result<Option<InstrumentAny>> {
+        PostgresCacheDatabase::load_instrument(self, instrument_id).await
+    }
+
+    async fn load_instruments(&self) -> anyhow::Result<Vec<InstrumentAny>> {
+        PostgresCacheDatabase::load_instruments(self).await
+    }
+
+    async fn add_quote(&self, quote: QuoteTick) -> anyhow::Result<()> {
+        PostgresCacheDatabase::add_quote(self, quote).await
+    }
+
+    async fn load_quotes(
+        &self,
+        instrument_id: &InstrumentId,
+        start_ns: UnixNanos,
+        end_ns: UnixNanos,
+    ) -> anyhow::Result<Vec<QuoteTick>> {
+        PostgresCacheDatabase::load_quotes(self, instrument_id, start_ns, end_ns).await
+    }
+
+    async fn flush(&self) -> anyhow::Result<()> {
+        PostgresCacheDatabase::flush(self).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_send_query_saturated_when_channel_full() {
+        let (tx, _rx) = channel::<DatabaseQuery>(1);
+        send_query(&tx, DatabaseQuery::Add("a".to_string(), vec![])).unwrap();
+
+        let err = send_query(&tx, DatabaseQuery::Add("b".to_string(), vec![])).unwrap_err();
+        assert!(matches!(
+            err.downcast_ref::<CacheDatabaseError>(),
+            Some(CacheDatabaseError::Saturated)
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_send_query_closed_when_receiver_dropped() {
+        let (tx, rx) = channel::<DatabaseQuery>(1);
+        drop(rx);
+
+        let err = send_query(&tx, DatabaseQuery::Add("a".to_string(), vec![])).unwrap_err();
+        assert!(matches!(
+            err.downcast_ref::<CacheDatabaseError>(),
+            Some(CacheDatabaseError::Closed)
+        ));
+    }
 }
@@ -0,0 +1,81 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Backend-agnostic cache persistence, so the engine can hold a `Box<dyn CacheDatabase>`
+//! and swap Postgres and Redis without matching on the concrete type at every call site.
+//!
+//! `CacheDatabase` is hand-written with one method per table rather than a generic
+//! blueprint-derived read/write adapter: every write here goes through
+//! [`PostgresCacheDatabase`](super::cache_database::PostgresCacheDatabase)'s buffered drain task
+//! and lands as a multi-row batch insert, not a per-row write. A blanket single-row adapter
+//! doesn't compose with that — it would either bypass the batching or have to be reimplemented
+//! on top of it, at which point it's no longer the generic adapter it set out to be. An earlier
+//! pass added such a layer anyway and it was reverted once nothing but the buffered batch API
+//! above ended up calling it.
+
+use std::collections::HashMap;
+
+use async_trait::async_trait;
+use nautilus_core::time::UnixNanos;
+use nautilus_model::{
+    data::quote::QuoteTick, identifiers::instrument_id::InstrumentId, instruments::InstrumentAny,
+    types::currency::Currency,
+};
+
+/// Common surface every cache persistence backend (Postgres, Redis, ...) must provide.
+#[async_trait]
+pub trait CacheDatabase: Send + Sync {
+    /// Load every key/value pair out of the `general` table.
+    async fn load(&self) -> anyhow::Result<HashMap<String, Vec<u8>>>;
+
+    /// Queue an opaque key/value pair for persistence.
+    async fn add(&self, key: String, value: Vec<u8>) -> anyhow::Result<()>;
+
+    /// Queue a [`Currency`] for persistence.
+    async fn add_currency(&self, currency: Currency) -> anyhow::Result<()>;
+
+    /// Load a single currency by its code.
+    async fn load_currency(&self, code: &str) -> anyhow::Result<Option<Currency>>;
+
+    /// Load every persisted currency.
+    async fn load_currencies(&self) -> anyhow::Result<Vec<Currency>>;
+
+    /// Queue an [`InstrumentAny`] for persistence.
+    async fn add_instrument(&self, instrument: InstrumentAny) -> anyhow::Result<()>;
+
+    /// Load a single instrument by ID.
+    async fn load_instrument(
+        &self,
+        instrument_id: InstrumentId,
+    ) -> anyhow::Result<Option<InstrumentAny>>;
+
+    /// Load every persisted instrument.
+    async fn load_instruments(&self) -> anyhow::Result<Vec<InstrumentAny>>;
+
+    /// Queue a [`QuoteTick`] for persistence in the `quote_tick` time-series table.
+    async fn add_quote(&self, quote: QuoteTick) -> anyhow::Result<()>;
+
+    /// Load every `quote_tick` row for `instrument_id` with `ts_event` in `[start_ns, end_ns]`,
+    /// ordered by `ts_event` ascending.
+    async fn load_quotes(
+        &self,
+        instrument_id: &InstrumentId,
+        start_ns: UnixNanos,
+        end_ns: UnixNanos,
+    ) -> anyhow::Result<Vec<QuoteTick>>;
+
+    /// Blocks until every query queued so far has been durably written, for use at shutdown.
+    async fn flush(&self) -> anyhow::Result<()>;
+}
@@ -0,0 +1,244 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! An append-only Merkle tree over the `general` key/value table, so operators can detect
+//! silent corruption or unauthorized edits of persisted state.
+//!
+//! Inspired by Fuel-core's "Merklized" insertion-only blueprint: every `(key, value)` pair
+//! written through [`super::cache_database::PostgresCacheDatabase::add`] becomes a leaf, leaves
+//! are always visited in sorted-by-key order so the root is reproducible across replays, and
+//! node hashes are folded pairwise up to a single root.
+
+use std::collections::BTreeMap;
+
+use sha2::{Digest, Sha256};
+
+/// A 32-byte SHA-256 node hash.
+pub type Hash = [u8; 32];
+
+fn hash_leaf(key: &str, value: &[u8]) -> Hash {
+    let mut hasher = Sha256::new();
+    hasher.update(key.as_bytes());
+    hasher.update(value);
+    hasher.finalize().into()
+}
+
+fn hash_pair(left: &Hash, right: &Hash) -> Hash {
+    let mut hasher = Sha256::new();
+    hasher.update(left);
+    hasher.update(right);
+    hasher.finalize().into()
+}
+
+/// A sibling hash and whether it sits to the left (`true`) or right (`false`) of the node on
+/// the path being proven, i.e. how to fold it with the accumulator while walking to the root.
+pub type ProofStep = (Hash, bool);
+
+/// An append-only Merkle tree over the `general` table's key/value pairs.
+///
+/// The tree only grows: keys are never removed, only inserted or overwritten in place. Leaves
+/// are kept in a [`BTreeMap`] so iteration is always sorted by key, making the root and every
+/// proof deterministic and reproducible across replays.
+#[derive(Debug, Clone, Default)]
+pub struct MerkleTree {
+    leaves: BTreeMap<String, Hash>,
+    /// `levels[0]` is the leaf level, `levels.last()` is the single root (once non-empty).
+    levels: Vec<Vec<Hash>>,
+}
+
+impl MerkleTree {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Rebuilds a tree from previously persisted leaf hashes, e.g. on startup, without
+    /// re-hashing the original values (which the side table doesn't store). The root and every
+    /// proof are reproducible from the leaf hashes alone, so this yields the same tree as if
+    /// every `insert` that produced them had just been replayed.
+    #[must_use]
+    pub fn from_leaf_hashes(leaves: impl IntoIterator<Item = (String, Hash)>) -> Self {
+        let mut tree = Self {
+            leaves: leaves.into_iter().collect(),
+            levels: Vec::new(),
+        };
+        tree.rebuild();
+        tree
+    }
+
+    /// Inserts or overwrites the leaf for `key` with the hash of `(key, value)`, then
+    /// recomputes every level above it.
+    pub fn insert(&mut self, key: String, value: &[u8]) {
+        let leaf_hash = hash_leaf(&key, value);
+        self.leaves.insert(key, leaf_hash);
+        self.rebuild();
+    }
+
+    fn rebuild(&mut self) {
+        let leaf_level: Vec<Hash> = self.leaves.values().copied().collect();
+        let mut levels = vec![leaf_level];
+
+        while levels.last().is_some_and(|level| level.len() > 1) {
+            let prev = levels.last().unwrap();
+            let mut next = Vec::with_capacity(prev.len().div_ceil(2));
+            for pair in prev.chunks(2) {
+                let hash = match pair {
+                    [left, right] => hash_pair(left, right),
+                    // Odd node at this level: carry it up unchanged.
+                    [only] => *only,
+                    _ => unreachable!(),
+                };
+                next.push(hash);
+            }
+            levels.push(next);
+        }
+
+        self.levels = levels;
+    }
+
+    /// Returns the current Merkle root, or `None` if the tree is empty.
+    #[must_use]
+    pub fn root(&self) -> Option<Hash> {
+        self.levels.last().and_then(|level| level.first()).copied()
+    }
+
+    /// Returns the leaf hash stored for `key`, if it has been inserted.
+    #[must_use]
+    pub fn leaf_hash(&self, key: &str) -> Option<Hash> {
+        self.leaves.get(key).copied()
+    }
+
+    /// Returns the sibling hashes (and left/right flags) along the path from `key`'s leaf up
+    /// to the root, or `None` if `key` hasn't been inserted.
+    #[must_use]
+    pub fn proof(&self, key: &str) -> Option<Vec<ProofStep>> {
+        let mut index = self.leaves.keys().position(|k| k == key)?;
+        let mut proof = Vec::new();
+
+        for level in &self.levels {
+            if level.len() <= 1 {
+                break;
+            }
+            let sibling_index = index ^ 1;
+            if let Some(sibling) = level.get(sibling_index) {
+                // `sibling_index < index` means the sibling is the left node.
+                proof.push((*sibling, sibling_index < index));
+            }
+            index /= 2;
+        }
+
+        Some(proof)
+    }
+}
+
+/// Recomputes a Merkle root from `key`/`value` up through `proof`, and returns whether it
+/// matches `root`. This lets a caller verify a single leaf without holding the whole tree.
+#[must_use]
+pub fn verify(root: Hash, key: &str, value: &[u8], proof: &[ProofStep]) -> bool {
+    let mut acc = hash_leaf(key, value);
+    for (sibling, sibling_is_left) in proof {
+        acc = if *sibling_is_left {
+            hash_pair(sibling, &acc)
+        } else {
+            hash_pair(&acc, sibling)
+        };
+    }
+    acc == root
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_empty_tree_has_no_root() {
+        let tree = MerkleTree::new();
+        assert_eq!(tree.root(), None);
+    }
+
+    #[test]
+    fn test_single_leaf_root_is_its_hash() {
+        let mut tree = MerkleTree::new();
+        tree.insert("a".to_string(), b"1");
+        assert_eq!(tree.root(), Some(hash_leaf("a", b"1")));
+    }
+
+    #[test]
+    fn test_proof_verifies_against_root() {
+        let mut tree = MerkleTree::new();
+        for (key, value) in [("a", b"1"), ("b", b"2"), ("c", b"3"), ("d", b"4")] {
+            tree.insert(key.to_string(), value);
+        }
+
+        let root = tree.root().unwrap();
+        for (key, value) in [("a", b"1"), ("b", b"2"), ("c", b"3"), ("d", b"4")] {
+            let proof = tree.proof(key).unwrap();
+            assert!(verify(root, key, value, &proof));
+        }
+    }
+
+    #[test]
+    fn test_proof_rejects_tampered_value() {
+        let mut tree = MerkleTree::new();
+        for (key, value) in [("a", b"1"), ("b", b"2"), ("c", b"3")] {
+            tree.insert(key.to_string(), value);
+        }
+
+        let root = tree.root().unwrap();
+        let proof = tree.proof("a").unwrap();
+        assert!(!verify(root, "a", b"tampered", &proof));
+    }
+
+    #[test]
+    fn test_root_reproducible_regardless_of_insertion_order() {
+        let mut forward = MerkleTree::new();
+        for (key, value) in [("a", b"1"), ("b", b"2"), ("c", b"3")] {
+            forward.insert(key.to_string(), value);
+        }
+
+        let mut backward = MerkleTree::new();
+        for (key, value) in [("c", b"3"), ("b", b"2"), ("a", b"1")] {
+            backward.insert(key.to_string(), value);
+        }
+
+        assert_eq!(forward.root(), backward.root());
+    }
+
+    #[test]
+    fn test_from_leaf_hashes_reproduces_root_and_proofs() {
+        let mut original = MerkleTree::new();
+        for (key, value) in [("a", b"1"), ("b", b"2"), ("c", b"3"), ("d", b"4")] {
+            original.insert(key.to_string(), value);
+        }
+
+        let persisted: Vec<(String, Hash)> = original
+            .leaves
+            .iter()
+            .map(|(key, hash)| (key.clone(), *hash))
+            .collect();
+        let hydrated = MerkleTree::from_leaf_hashes(persisted);
+
+        assert_eq!(hydrated.root(), original.root());
+        for (key, value) in [("a", b"1"), ("b", b"2"), ("c", b"3"), ("d", b"4")] {
+            assert_eq!(hydrated.proof(key), original.proof(key));
+            assert!(verify(
+                hydrated.root().unwrap(),
+                key,
+                value,
+                &hydrated.proof(key).unwrap()
+            ));
+        }
+    }
+}
@@ -0,0 +1,38 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Row type for the `currency` table.
+//!
+//! The `code` column is broken out for indexing and `WHERE code = $1` lookups; the full
+//! `Currency` is otherwise stored as its JSON encoding so this row type doesn't need to track
+//! every field the domain type happens to have.
+
+use nautilus_model::types::currency::Currency;
+use sqlx::FromRow;
+
+/// A single row of the `currency` table.
+#[derive(Debug, Clone, FromRow)]
+pub struct CurrencyRow {
+    pub code: String,
+    pub payload: serde_json::Value,
+}
+
+impl TryFrom<CurrencyRow> for Currency {
+    type Error = serde_json::Error;
+
+    fn try_from(row: CurrencyRow) -> Result<Self, Self::Error> {
+        serde_json::from_value(row.payload)
+    }
+}
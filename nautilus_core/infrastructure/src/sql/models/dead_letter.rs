@@ -0,0 +1,27 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Row type for the `dead_letter` table: batches that never persisted after
+//! [`MAX_DRAIN_ATTEMPTS`](super::super::cache_database::MAX_DRAIN_ATTEMPTS), kept for inspection
+//! or replay instead of being dropped.
+
+use sqlx::FromRow;
+
+/// A single row of the `dead_letter` table.
+#[derive(Debug, Clone, FromRow)]
+pub struct DeadLetterRow {
+    pub kind: String,
+    pub payload: Vec<u8>,
+}
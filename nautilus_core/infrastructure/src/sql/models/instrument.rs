@@ -0,0 +1,41 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Row type for the `instrument` table.
+//!
+//! One table holds every [`InstrumentAny`](nautilus_model::instruments::InstrumentAny) variant:
+//! `kind` is the variant discriminator (e.g. `"CRYPTO_FUTURE"`) used to pick the right decode
+//! path, and `payload` is the instrument's JSON encoding.
+
+use nautilus_model::instruments::InstrumentAny;
+use sqlx::FromRow;
+
+use crate::sql::queries::DatabaseQueries;
+
+/// A single row of the `instrument` table.
+#[derive(Debug, Clone, FromRow)]
+pub struct InstrumentRow {
+    pub id: String,
+    pub kind: String,
+    pub payload: serde_json::Value,
+}
+
+impl TryFrom<InstrumentRow> for InstrumentAny {
+    type Error = anyhow::Error;
+
+    fn try_from(row: InstrumentRow) -> Result<Self, Self::Error> {
+        DatabaseQueries::decode_instrument(row)
+    }
+}
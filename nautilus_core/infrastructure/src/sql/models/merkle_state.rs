@@ -0,0 +1,46 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Row type for the `merkle_state` table, the side table the Merkle integrity subsystem uses to
+//! survive a process restart.
+//!
+//! Only leaf hashes are persisted, not the original values: [`MerkleTree::from_leaf_hashes`]
+//! rebuilds an identical tree (same root, same proofs) from the hashes alone, so there is no need
+//! to duplicate the `general` table's contents here.
+//!
+//! [`MerkleTree::from_leaf_hashes`]: super::super::merkle::MerkleTree::from_leaf_hashes
+
+use sqlx::FromRow;
+
+use crate::sql::merkle::Hash;
+
+/// A single row of the `merkle_state` table: the persisted leaf hash for one `general` key.
+#[derive(Debug, Clone, FromRow)]
+pub struct MerkleStateRow {
+    pub key: String,
+    pub hash: Vec<u8>,
+}
+
+impl TryFrom<MerkleStateRow> for (String, Hash) {
+    type Error = anyhow::Error;
+
+    fn try_from(row: MerkleStateRow) -> anyhow::Result<Self> {
+        let hash: Hash = row
+            .hash
+            .try_into()
+            .map_err(|bytes: Vec<u8>| anyhow::anyhow!("expected a 32-byte hash, got {}", bytes.len()))?;
+        Ok((row.key, hash))
+    }
+}
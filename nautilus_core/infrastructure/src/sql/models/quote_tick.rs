@@ -0,0 +1,53 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Row type for the `quote_tick` time-series table.
+//!
+//! `instrument_id` and `ts_event` are broken out into their own columns so range queries over a
+//! single instrument's history don't need to deserialize every row's payload first; the rest of
+//! the tick is stored as its JSON encoding.
+
+use nautilus_core::time::UnixNanos;
+use nautilus_model::data::quote::QuoteTick;
+use sqlx::FromRow;
+
+/// A single row of the `quote_tick` table.
+#[derive(Debug, Clone, FromRow)]
+pub struct QuoteTickRow {
+    pub instrument_id: String,
+    pub ts_event: i64,
+    pub payload: serde_json::Value,
+}
+
+impl TryFrom<QuoteTickRow> for QuoteTick {
+    type Error = serde_json::Error;
+
+    fn try_from(row: QuoteTickRow) -> Result<Self, Self::Error> {
+        serde_json::from_value(row.payload)
+    }
+}
+
+/// Splits `quote` into the indexed columns and JSON payload [`QuoteTickRow`] stores.
+pub fn encode_quote_tick(quote: &QuoteTick) -> anyhow::Result<(String, i64, serde_json::Value)> {
+    Ok((
+        quote.instrument_id.to_string(),
+        unix_nanos_as_i64(quote.ts_event),
+        serde_json::to_value(quote)?,
+    ))
+}
+
+fn unix_nanos_as_i64(ts: UnixNanos) -> i64 {
+    i64::try_from(u64::from(ts)).unwrap_or(i64::MAX)
+}
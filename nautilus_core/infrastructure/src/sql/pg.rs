@@ -0,0 +1,70 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Postgres connection setup shared by every entry point that talks to the cache database.
+
+use std::env;
+
+use sqlx::{postgres::PgConnectOptions, PgPool};
+
+const DEFAULT_POSTGRES_HOST: &str = "localhost";
+const DEFAULT_POSTGRES_PORT: u16 = 5432;
+const DEFAULT_POSTGRES_USERNAME: &str = "postgres";
+const DEFAULT_POSTGRES_DATABASE: &str = "nautilus";
+
+/// Builds [`PgConnectOptions`] from the given overrides, falling back first to the
+/// `PG_HOST`/`PG_PORT`/`PG_USERNAME`/`PG_PASSWORD`/`PG_DATABASE` environment variables and then
+/// to the defaults above, so callers only need to pass the values they actually want to
+/// override.
+pub fn get_postgres_connect_options(
+    host: Option<String>,
+    port: Option<u16>,
+    username: Option<String>,
+    password: Option<String>,
+    database: Option<String>,
+) -> anyhow::Result<PgConnectOptions> {
+    let host = host
+        .or_else(|| env::var("PG_HOST").ok())
+        .unwrap_or_else(|| DEFAULT_POSTGRES_HOST.to_string());
+    let port = port
+        .or_else(|| env::var("PG_PORT").ok().and_then(|s| s.parse().ok()))
+        .unwrap_or(DEFAULT_POSTGRES_PORT);
+    let username = username
+        .or_else(|| env::var("PG_USERNAME").ok())
+        .unwrap_or_else(|| DEFAULT_POSTGRES_USERNAME.to_string());
+    let password = password.or_else(|| env::var("PG_PASSWORD").ok());
+    let database = database
+        .or_else(|| env::var("PG_DATABASE").ok())
+        .unwrap_or_else(|| DEFAULT_POSTGRES_DATABASE.to_string());
+
+    let mut options = PgConnectOptions::new()
+        .host(&host)
+        .port(port)
+        .username(&username)
+        .database(&database);
+    if let Some(password) = password {
+        options = options.password(&password);
+    }
+    Ok(options)
+}
+
+/// Opens a connection pool against `options` and applies every pending migration under
+/// `migrations/`, so a fresh database is brought up to the schema the cache layer expects
+/// before the pool is handed back to the caller.
+pub async fn connect_pg(options: PgConnectOptions) -> anyhow::Result<PgPool> {
+    let pool = PgPool::connect_with(options).await?;
+    sqlx::migrate!("migrations").run(&pool).await?;
+    Ok(pool)
+}
@@ -0,0 +1,281 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Hand-written SQL for every table [`super::cache_database::PostgresCacheDatabase`] persists or
+//! reads, kept apart from the drain/retry orchestration so the SQL itself is easy to read and
+//! review on its own.
+
+use std::collections::HashMap;
+
+use nautilus_core::time::UnixNanos;
+use nautilus_model::{
+    data::quote::QuoteTick,
+    identifiers::instrument_id::InstrumentId,
+    instruments::{Instrument, InstrumentAny},
+    types::currency::Currency,
+};
+use sqlx::{PgPool, Postgres, QueryBuilder, Transaction};
+
+use super::{
+    merkle::Hash,
+    models::{
+        currency::CurrencyRow, dead_letter::DeadLetterRow, general::GeneralRow,
+        instrument::InstrumentRow, merkle_state::MerkleStateRow,
+        quote_tick::{encode_quote_tick, QuoteTickRow},
+    },
+};
+
+/// Hand-written SQL for every table the cache database persists or reads.
+pub struct DatabaseQueries;
+
+impl DatabaseQueries {
+    /// Inserts or overwrites `rows` in the `general` table within `tx`, as one multi-row
+    /// `INSERT ... ON CONFLICT`, so the caller can fold it into a larger transaction (for
+    /// example, alongside a Merkle state update that must land atomically with the same rows).
+    pub async fn copy_general_batch(
+        tx: &mut Transaction<'_, Postgres>,
+        rows: Vec<(String, Vec<u8>)>,
+    ) -> Result<(), sqlx::Error> {
+        if rows.is_empty() {
+            return Ok(());
+        }
+        // Postgres rejects an `ON CONFLICT DO UPDATE` that touches the same row twice in one
+        // statement, so keep only the last value queued for each key.
+        let rows: HashMap<String, Vec<u8>> = rows.into_iter().collect();
+        let mut builder = QueryBuilder::new("INSERT INTO general (key, value) ");
+        builder.push_values(rows, |mut b, (key, value)| {
+            b.push_bind(key).push_bind(value);
+        });
+        builder.push(" ON CONFLICT (key) DO UPDATE SET value = EXCLUDED.value");
+        builder.build().execute(&mut **tx).await?;
+        Ok(())
+    }
+
+    /// Loads the full `general` table.
+    pub async fn load_general(pool: &PgPool) -> Result<Vec<GeneralRow>, sqlx::Error> {
+        sqlx::query_as::<_, GeneralRow>("SELECT * FROM general")
+            .fetch_all(pool)
+            .await
+    }
+
+    /// Upserts `leaves`' hashes into the `merkle_state` side table within `tx`, so the Merkle
+    /// integrity tree can be rebuilt on the next [`PostgresCacheDatabase::connect`] call instead
+    /// of resetting across a process restart.
+    ///
+    /// [`PostgresCacheDatabase::connect`]: super::cache_database::PostgresCacheDatabase::connect
+    pub async fn persist_merkle_state(
+        tx: &mut Transaction<'_, Postgres>,
+        leaves: Vec<(String, Hash)>,
+    ) -> Result<(), sqlx::Error> {
+        if leaves.is_empty() {
+            return Ok(());
+        }
+        // One row per key, for the same `ON CONFLICT DO UPDATE` reason as `copy_general_batch`.
+        let leaves: HashMap<String, Hash> = leaves.into_iter().collect();
+        let mut builder = QueryBuilder::new("INSERT INTO merkle_state (key, hash) ");
+        builder.push_values(leaves, |mut b, (key, hash)| {
+            b.push_bind(key).push_bind(hash.to_vec());
+        });
+        builder.push(" ON CONFLICT (key) DO UPDATE SET hash = EXCLUDED.hash");
+        builder.build().execute(&mut **tx).await?;
+        Ok(())
+    }
+
+    /// Loads every persisted Merkle leaf hash, so the caller can rebuild the tree via
+    /// [`super::merkle::MerkleTree::from_leaf_hashes`].
+    pub async fn load_merkle_state(pool: &PgPool) -> anyhow::Result<Vec<(String, Hash)>> {
+        let rows = sqlx::query_as::<_, MerkleStateRow>("SELECT * FROM merkle_state")
+            .fetch_all(pool)
+            .await?;
+        rows.into_iter().map(TryFrom::try_from).collect()
+    }
+
+    /// Inserts or overwrites `rows` in the `currency` table as one multi-row
+    /// `INSERT ... ON CONFLICT`.
+    pub async fn add_currency_batch(pool: &PgPool, rows: Vec<Currency>) -> anyhow::Result<()> {
+        if rows.is_empty() {
+            return Ok(());
+        }
+        // One row per code (the last one queued), for the same `ON CONFLICT DO UPDATE` reason as
+        // `copy_general_batch`.
+        let encoded: HashMap<String, serde_json::Value> = rows
+            .iter()
+            .map(|currency| Ok((currency.code.to_string(), serde_json::to_value(currency)?)))
+            .collect::<Result<_, serde_json::Error>>()?;
+        let mut builder = QueryBuilder::new("INSERT INTO currency (code, payload) ");
+        builder.push_values(encoded, |mut b, (code, payload)| {
+            b.push_bind(code).push_bind(payload);
+        });
+        builder.push(" ON CONFLICT (code) DO UPDATE SET payload = EXCLUDED.payload");
+        builder.build().execute(pool).await?;
+        Ok(())
+    }
+
+    pub async fn load_currencies(pool: &PgPool) -> anyhow::Result<Vec<Currency>> {
+        let rows = sqlx::query_as::<_, CurrencyRow>("SELECT * FROM currency")
+            .fetch_all(pool)
+            .await?;
+        Ok(rows
+            .into_iter()
+            .map(Currency::try_from)
+            .collect::<Result<_, _>>()?)
+    }
+
+    pub async fn load_currency(pool: &PgPool, code: &str) -> anyhow::Result<Option<Currency>> {
+        let row = sqlx::query_as::<_, CurrencyRow>("SELECT * FROM currency WHERE code = $1")
+            .bind(code)
+            .fetch_optional(pool)
+            .await?;
+        Ok(row.map(Currency::try_from).transpose()?)
+    }
+
+    /// Inserts or overwrites `rows` of instrument kind `kind` as one multi-row
+    /// `INSERT ... ON CONFLICT`, keyed by each instrument's [`InstrumentId`].
+    pub async fn add_instrument_batch<T>(
+        pool: &PgPool,
+        kind: &'static str,
+        rows: Vec<T>,
+    ) -> anyhow::Result<()>
+    where
+        T: Instrument + serde::Serialize,
+    {
+        if rows.is_empty() {
+            return Ok(());
+        }
+        // One row per id (the last one queued), for the same `ON CONFLICT DO UPDATE` reason as
+        // `copy_general_batch`.
+        let encoded: HashMap<String, serde_json::Value> = rows
+            .iter()
+            .map(|instrument| {
+                Ok((instrument.id().to_string(), serde_json::to_value(instrument)?))
+            })
+            .collect::<Result<_, serde_json::Error>>()?;
+        let mut builder = QueryBuilder::new("INSERT INTO instrument (id, kind, payload) ");
+        builder.push_values(encoded, |mut b, (id, payload)| {
+            b.push_bind(id).push_bind(kind).push_bind(payload);
+        });
+        builder.push(
+            " ON CONFLICT (id) DO UPDATE SET kind = EXCLUDED.kind, payload = EXCLUDED.payload",
+        );
+        builder.build().execute(pool).await?;
+        Ok(())
+    }
+
+    /// Decodes `row`'s JSON payload back into the [`InstrumentAny`] variant its `kind` names.
+    pub(crate) fn decode_instrument(row: InstrumentRow) -> anyhow::Result<InstrumentAny> {
+        Ok(match row.kind.as_str() {
+            "CRYPTO_FUTURE" => InstrumentAny::CryptoFuture(serde_json::from_value(row.payload)?),
+            "CRYPTO_PERPETUAL" => {
+                InstrumentAny::CryptoPerpetual(serde_json::from_value(row.payload)?)
+            }
+            "CURRENCY_PAIR" => InstrumentAny::CurrencyPair(serde_json::from_value(row.payload)?),
+            "EQUITY" => InstrumentAny::Equity(serde_json::from_value(row.payload)?),
+            "FUTURES_CONTRACT" => {
+                InstrumentAny::FuturesContract(serde_json::from_value(row.payload)?)
+            }
+            "FUTURES_SPREAD" => InstrumentAny::FuturesSpread(serde_json::from_value(row.payload)?),
+            "OPTIONS_CONTRACT" => {
+                InstrumentAny::OptionsContract(serde_json::from_value(row.payload)?)
+            }
+            "OPTIONS_SPREAD" => InstrumentAny::OptionsSpread(serde_json::from_value(row.payload)?),
+            other => anyhow::bail!("Unknown instrument kind `{other}`"),
+        })
+    }
+
+    pub async fn load_instrument(
+        pool: &PgPool,
+        instrument_id: InstrumentId,
+    ) -> anyhow::Result<Option<InstrumentAny>> {
+        let row = sqlx::query_as::<_, InstrumentRow>("SELECT * FROM instrument WHERE id = $1")
+            .bind(instrument_id.to_string())
+            .fetch_optional(pool)
+            .await?;
+        row.map(Self::decode_instrument).transpose()
+    }
+
+    pub async fn load_instruments(pool: &PgPool) -> anyhow::Result<Vec<InstrumentAny>> {
+        let rows = sqlx::query_as::<_, InstrumentRow>("SELECT * FROM instrument")
+            .fetch_all(pool)
+            .await?;
+        rows.into_iter().map(Self::decode_instrument).collect()
+    }
+
+    /// Inserts `rows` into the `quote_tick` table as one multi-row `INSERT ... ON CONFLICT DO
+    /// NOTHING`. Quote ticks are immutable, so a re-send of an already-committed row from a
+    /// retried drain (see [`super::cache_database::drain_group_with_retry`]) is a harmless
+    /// no-op rather than a duplicate.
+    pub async fn add_quote_batch(pool: &PgPool, rows: Vec<QuoteTick>) -> anyhow::Result<()> {
+        if rows.is_empty() {
+            return Ok(());
+        }
+        let encoded: Vec<(String, i64, serde_json::Value)> =
+            rows.iter().map(encode_quote_tick).collect::<anyhow::Result<_>>()?;
+        let mut builder =
+            QueryBuilder::new("INSERT INTO quote_tick (instrument_id, ts_event, payload) ");
+        builder.push_values(encoded, |mut b, (instrument_id, ts_event, payload)| {
+            b.push_bind(instrument_id).push_bind(ts_event).push_bind(payload);
+        });
+        builder.push(" ON CONFLICT (instrument_id, ts_event) DO NOTHING");
+        builder.build().execute(pool).await?;
+        Ok(())
+    }
+
+    /// Loads every `quote_tick` row for `instrument_id` with `ts_event` in `[start_ns, end_ns]`,
+    /// ordered by `ts_event` ascending.
+    pub async fn load_quotes(
+        pool: &PgPool,
+        instrument_id: &InstrumentId,
+        start_ns: UnixNanos,
+        end_ns: UnixNanos,
+    ) -> anyhow::Result<Vec<QuoteTick>> {
+        let rows = sqlx::query_as::<_, QuoteTickRow>(
+            "SELECT * FROM quote_tick \
+             WHERE instrument_id = $1 AND ts_event BETWEEN $2 AND $3 \
+             ORDER BY ts_event ASC",
+        )
+        .bind(instrument_id.to_string())
+        .bind(i64::try_from(u64::from(start_ns)).unwrap_or(i64::MAX))
+        .bind(i64::try_from(u64::from(end_ns)).unwrap_or(i64::MAX))
+        .fetch_all(pool)
+        .await?;
+        rows.into_iter()
+            .map(|row| QuoteTick::try_from(row).map_err(anyhow::Error::from))
+            .collect()
+    }
+
+    /// Appends a dead-lettered batch of `kind` rows (pre-serialized by the caller) to the
+    /// `dead_letter` table.
+    pub async fn add_dead_letter(
+        pool: &PgPool,
+        kind: &str,
+        payload: Vec<u8>,
+    ) -> Result<(), sqlx::Error> {
+        sqlx::query("INSERT INTO dead_letter (kind, payload) VALUES ($1, $2)")
+            .bind(kind)
+            .bind(payload)
+            .execute(pool)
+            .await?;
+        Ok(())
+    }
+
+    /// Loads every dead-lettered batch for inspection or replay, most recent first.
+    pub async fn load_dead_letter(pool: &PgPool) -> Result<Vec<DeadLetterRow>, sqlx::Error> {
+        sqlx::query_as::<_, DeadLetterRow>(
+            "SELECT kind, payload FROM dead_letter ORDER BY id DESC",
+        )
+        .fetch_all(pool)
+        .await
+    }
+}
@@ -16,10 +16,11 @@
 use std::{
     cmp,
     fmt::{Display, Formatter},
+    str::FromStr,
 };
 
 use nautilus_core::{correctness, time::UnixNanos};
-use pyo3::{prelude::*, pyclass::CompareOp, types::PyDict};
+use pyo3::{exceptions::PyValueError, prelude::*, pyclass::CompareOp, types::PyDict};
 use serde::{Deserialize, Serialize};
 
 use crate::{
@@ -98,6 +99,48 @@ impl QuoteTick {
     fn to_msgpack_bytes(&self) -> Vec<u8> {
         rmp_serde::to_vec(self).unwrap()
     }
+
+    /// Return a [`QuoteTick`] decoded from JSON encoded `bytes`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `bytes` is not valid JSON for a [`QuoteTick`], or if the decoded
+    /// tick fails the same precision-equality checks enforced by [`QuoteTick::new`].
+    pub fn from_json_bytes(bytes: &[u8]) -> anyhow::Result<Self> {
+        let tick: Self = serde_json::from_slice(bytes)?;
+        tick.check_precision_equality()?;
+        Ok(tick)
+    }
+
+    /// Return a [`QuoteTick`] decoded from MsgPack encoded `bytes`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `bytes` is not valid MsgPack for a [`QuoteTick`], or if the decoded
+    /// tick fails the same precision-equality checks enforced by [`QuoteTick::new`].
+    pub fn from_msgpack_bytes(bytes: &[u8]) -> anyhow::Result<Self> {
+        let tick: Self = rmp_serde::from_slice(bytes)?;
+        tick.check_precision_equality()?;
+        Ok(tick)
+    }
+
+    /// Re-runs the precision-equality invariants checked in [`QuoteTick::new`], but as a
+    /// `Result` rather than a panic, for ticks decoded from an untrusted byte source.
+    fn check_precision_equality(&self) -> anyhow::Result<()> {
+        anyhow::ensure!(
+            self.bid.precision == self.ask.precision,
+            "bid.precision {} must equal ask.precision {}",
+            self.bid.precision,
+            self.ask.precision
+        );
+        anyhow::ensure!(
+            self.bid_size.precision == self.ask_size.precision,
+            "bid_size.precision {} must equal ask_size.precision {}",
+            self.bid_size.precision,
+            self.ask_size.precision
+        );
+        Ok(())
+    }
 }
 
 impl Display for QuoteTick {
@@ -218,6 +261,53 @@ impl QuoteTick {
     fn to_msgpack(&self) -> Py<PyAny> {
         Python::with_gil(|py| self.to_msgpack_bytes().into_py(py))
     }
+
+    /// Return a [`QuoteTick`] reconstructed from a `to_dict` style dictionary.
+    #[staticmethod]
+    fn from_dict(values: Py<PyDict>) -> PyResult<Self> {
+        Python::with_gil(|py| {
+            let dict = values.as_ref(py);
+
+            let get = |key: &str| -> PyResult<String> {
+                dict.get_item(key)?
+                    .ok_or_else(|| PyValueError::new_err(format!("`{key}` missing from dict")))?
+                    .extract()
+            };
+
+            let instrument_id = InstrumentId::from_str(&get("instrument_id")?)
+                .map_err(|e| PyValueError::new_err(e.to_string()))?;
+            let bid = Price::from_str(&get("bid")?).map_err(|e| PyValueError::new_err(e.to_string()))?;
+            let ask = Price::from_str(&get("ask")?).map_err(|e| PyValueError::new_err(e.to_string()))?;
+            let bid_size = Quantity::from_str(&get("bid_size")?)
+                .map_err(|e| PyValueError::new_err(e.to_string()))?;
+            let ask_size = Quantity::from_str(&get("ask_size")?)
+                .map_err(|e| PyValueError::new_err(e.to_string()))?;
+            let ts_event: UnixNanos = dict
+                .get_item("ts_event")?
+                .ok_or_else(|| PyValueError::new_err("`ts_event` missing from dict"))?
+                .extract()?;
+            let ts_init: UnixNanos = dict
+                .get_item("ts_init")?
+                .ok_or_else(|| PyValueError::new_err("`ts_init` missing from dict"))?
+                .extract()?;
+
+            // Bypass `Self::new`'s panicking assertions: a malformed dict from Python should
+            // raise a `PyValueError` here, the same as every other validation failure in this
+            // function, rather than panicking through the pyo3 boundary.
+            let tick = Self {
+                instrument_id,
+                bid,
+                ask,
+                bid_size,
+                ask_size,
+                ts_event,
+                ts_init,
+            };
+            tick.check_precision_equality()
+                .map_err(|e| PyValueError::new_err(e.to_string()))?;
+            Ok(tick)
+        })
+    }
 }
 
 ////////////////////////////////////////////////////////////////////////////////
@@ -274,4 +364,44 @@ mod tests {
         let result = tick.extract_price(input).raw;
         assert_eq!(result, expected);
     }
+
+    #[test]
+    fn test_from_json_bytes_round_trip() {
+        let tick = QuoteTick {
+            instrument_id: InstrumentId::from_str("ETHUSDT-PERP.BINANCE").unwrap(),
+            bid: Price::new(10000.0, 4),
+            ask: Price::new(10001.0, 4),
+            bid_size: Quantity::new(1.0, 8),
+            ask_size: Quantity::new(1.0, 8),
+            ts_event: 0,
+            ts_init: 0,
+        };
+
+        let bytes = tick.to_json_bytes();
+        let result = QuoteTick::from_json_bytes(&bytes).unwrap();
+        assert_eq!(result, tick);
+    }
+
+    #[test]
+    fn test_from_msgpack_bytes_round_trip() {
+        let tick = QuoteTick {
+            instrument_id: InstrumentId::from_str("ETHUSDT-PERP.BINANCE").unwrap(),
+            bid: Price::new(10000.0, 4),
+            ask: Price::new(10001.0, 4),
+            bid_size: Quantity::new(1.0, 8),
+            ask_size: Quantity::new(1.0, 8),
+            ts_event: 0,
+            ts_init: 0,
+        };
+
+        let bytes = tick.to_msgpack_bytes();
+        let result = QuoteTick::from_msgpack_bytes(&bytes).unwrap();
+        assert_eq!(result, tick);
+    }
+
+    #[test]
+    fn test_from_json_bytes_invalid() {
+        let result = QuoteTick::from_json_bytes(b"not json");
+        assert!(result.is_err());
+    }
 }